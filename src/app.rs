@@ -0,0 +1,586 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use ratatui::widgets::ListState;
+use walkdir::WalkDir;
+
+use crate::git::GitEntry;
+use crate::highlight::Highlighter;
+use crate::keymap::Keymap;
+
+// --- Data Structures ---
+
+#[derive(PartialEq)]
+pub enum Focus {
+    FileList,
+    Editor,
+    Log,
+    Git,
+}
+
+/// An editor change expressed as inserted/removed text at a `(row, col)` position,
+/// so undo/redo can store diffs instead of full buffer snapshots.
+#[derive(Clone)]
+pub enum EditOp {
+    Insert { row: usize, col: usize, text: String },
+    Delete { row: usize, col: usize, text: String },
+}
+
+impl EditOp {
+    fn inverse(&self) -> EditOp {
+        match self {
+            EditOp::Insert { row, col, text } => EditOp::Delete { row: *row, col: *col, text: text.clone() },
+            EditOp::Delete { row, col, text } => EditOp::Insert { row: *row, col: *col, text: text.clone() },
+        }
+    }
+}
+
+/// Maximum number of undo groups kept around; older ones are dropped.
+const MAX_UNDO: usize = 500;
+
+pub struct App {
+    pub project_path: PathBuf,
+    pub status: String,
+    pub logs: Vec<String>,
+    pub files: Vec<PathBuf>,
+    pub file_list_state: ListState,
+    /// Buffer contents, one entry per line. Always has at least one (possibly empty) line.
+    pub lines: Vec<String>,
+    /// (row, col) of the cursor inside `lines`. `col` is a char index into the line, not a
+    /// byte offset, so it stays correct against multibyte characters.
+    pub cursor: (usize, usize),
+    /// First line of `lines` shown at the top of the editor viewport.
+    pub editor_scroll: usize,
+    /// True when the buffer has unsaved changes.
+    pub dirty: bool,
+    /// Undo/redo history, stored as the diffs needed to reverse/replay edits.
+    pub undo: Vec<EditOp>,
+    pub redo: Vec<EditOp>,
+    /// True while consecutive single-character insertions should merge into one undo group.
+    coalescing: bool,
+    /// Path of the file currently loaded into `lines`, if any.
+    pub current_file: Option<PathBuf>,
+    /// Path and mtime of the last file we wrote via `save_current_file`, so the watcher event
+    /// that save produces can be told apart from a genuine external change.
+    self_write: Option<(PathBuf, SystemTime)>,
+    /// Loaded once and reused across redraws.
+    pub highlighter: Highlighter,
+    /// Whether the editor renders with Markdown syntax highlighting.
+    pub highlight_enabled: bool,
+    /// Active key bindings, loaded from config at startup.
+    pub keymap: Keymap,
+    /// Help Bar text, regenerated from `keymap` so it always reflects the active bindings.
+    pub help_text: String,
+    /// Parsed `git status --porcelain` entries, refreshed when the Git panel is opened with
+    /// the `OpenGit` action and after each stage, unstage or commit.
+    pub git_entries: Vec<GitEntry>,
+    pub git_list_state: ListState,
+    /// Plain unified diff of the selected entry, one line each; colorized for display by
+    /// inspecting each line's `+`/`-`/`@@` prefix rather than ANSI escapes.
+    pub git_diff: Vec<String>,
+    pub git_diff_scroll: usize,
+    /// Commit message being typed, `Some` while the commit prompt is open.
+    pub git_commit_input: Option<String>,
+    pub focus: Focus,
+    pub should_quit: bool,
+}
+
+impl App {
+    pub fn new() -> std::io::Result<App> {
+        let path = std::env::current_dir()?;
+        let keymap = Keymap::load(&path);
+        let help_text = keymap.help_text();
+        let mut app = App {
+            project_path: path.clone(),
+            status: "OFFLINE".to_string(),
+            logs: vec!["[System] Manager started.".into()],
+            files: vec![],
+            file_list_state: ListState::default(),
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            editor_scroll: 0,
+            dirty: false,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            coalescing: false,
+            current_file: None,
+            self_write: None,
+            highlighter: Highlighter::new(),
+            highlight_enabled: false,
+            keymap,
+            help_text,
+            git_entries: Vec::new(),
+            git_list_state: ListState::default(),
+            git_diff: Vec::new(),
+            git_diff_scroll: 0,
+            git_commit_input: None,
+            focus: Focus::FileList,
+            should_quit: false,
+        };
+        app.refresh_files();
+        Ok(app)
+    }
+
+    /// Re-scans `project_path` for `.md`/`.mdx` files, preserving the current selection
+    /// (by path, not index) where the selected file still exists.
+    pub fn refresh_files(&mut self) {
+        let previous = self.file_list_state.selected().and_then(|i| self.files.get(i)).cloned();
+
+        self.files = WalkDir::new(&self.project_path)
+            .max_depth(3)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().map_or(false, |ext| ext == "md" || ext == "mdx"))
+            .map(|e| e.path().to_path_buf())
+            .collect();
+
+        let restored = previous.and_then(|p| self.files.iter().position(|f| *f == p));
+        match restored {
+            Some(i) => self.file_list_state.select(Some(i)),
+            None if !self.files.is_empty() => self.file_list_state.select(Some(0)),
+            None => self.file_list_state.select(None),
+        }
+    }
+
+    pub fn load_selected_file(&mut self) {
+        if let Some(i) = self.file_list_state.selected() {
+            if let Ok(content) = fs::read_to_string(&self.files[i]) {
+                self.lines = if content.is_empty() {
+                    vec![String::new()]
+                } else {
+                    content.lines().map(|l| l.to_string()).collect()
+                };
+                self.cursor = (0, 0);
+                self.editor_scroll = 0;
+                self.dirty = false;
+                self.undo.clear();
+                self.redo.clear();
+                self.coalescing = false;
+                self.current_file = Some(self.files[i].clone());
+                self.logs.push(format!("[File] Loaded {}", self.files[i].display()));
+            }
+        }
+    }
+
+    /// Reloads `path` from disk if it's the file currently open in the editor and there are
+    /// no unsaved local edits, logging the reason either way. A watcher event for a save we
+    /// just performed ourselves (matched by mtime) is ignored rather than treated as an
+    /// external change, so it doesn't reset the cursor and wipe undo history.
+    pub fn reload_if_current(&mut self, path: &std::path::Path) {
+        if self.current_file.as_deref() != Some(path) {
+            return;
+        }
+        if let Some((written_path, written_mtime)) = &self.self_write {
+            if written_path == path && fs::metadata(path).and_then(|m| m.modified()).ok().as_ref() == Some(written_mtime) {
+                self.self_write = None;
+                return;
+            }
+        }
+        if self.dirty {
+            self.logs.push(format!("[Watcher] {} changed on disk but has unsaved local edits; not reloading", path.display()));
+            return;
+        }
+        if let Ok(content) = fs::read_to_string(path) {
+            self.lines = if content.is_empty() {
+                vec![String::new()]
+            } else {
+                content.lines().map(|l| l.to_string()).collect()
+            };
+            self.cursor = (0, 0);
+            self.editor_scroll = 0;
+            self.undo.clear();
+            self.redo.clear();
+            self.coalescing = false;
+            self.logs.push(format!("[Watcher] Reloaded {} (changed externally)", path.display()));
+        }
+    }
+
+    pub fn save_current_file(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        if let Some(i) = self.file_list_state.selected() {
+            let path = self.files[i].clone();
+            let content = self.lines.join("\n");
+            if fs::write(&path, content).is_ok() {
+                self.dirty = false;
+                self.self_write = fs::metadata(&path).and_then(|m| m.modified()).ok().map(|mtime| (path.clone(), mtime));
+                self.logs.push(format!("[File] Saved {}", path.display()));
+            }
+        }
+    }
+
+    pub fn toggle_highlight(&mut self) {
+        self.highlight_enabled = !self.highlight_enabled;
+    }
+
+    // --- Git panel ---
+
+    pub fn set_git_status(&mut self, entries: Vec<GitEntry>) {
+        self.git_entries = entries;
+        if self.git_entries.is_empty() {
+            self.git_list_state.select(None);
+        } else if self.git_list_state.selected().is_none() {
+            self.git_list_state.select(Some(0));
+        } else {
+            let i = self.git_list_state.selected().unwrap().min(self.git_entries.len() - 1);
+            self.git_list_state.select(Some(i));
+        }
+    }
+
+    pub fn set_git_diff(&mut self, diff: String) {
+        self.git_diff = diff.lines().map(String::from).collect();
+        self.git_diff_scroll = 0;
+    }
+
+    pub fn git_select_up(&mut self) {
+        let i = self.git_list_state.selected().unwrap_or(0);
+        self.git_list_state.select(Some(i.saturating_sub(1)));
+    }
+
+    pub fn git_select_down(&mut self) {
+        if self.git_entries.is_empty() {
+            return;
+        }
+        let i = self.git_list_state.selected().unwrap_or(0);
+        self.git_list_state.select(Some((i + 1).min(self.git_entries.len() - 1)));
+    }
+
+    pub fn git_scroll_diff_up(&mut self) {
+        self.git_diff_scroll = self.git_diff_scroll.saturating_sub(1);
+    }
+
+    pub fn git_scroll_diff_down(&mut self) {
+        self.git_diff_scroll = (self.git_diff_scroll + 1).min(self.git_diff.len().saturating_sub(1));
+    }
+
+    pub fn open_commit_prompt(&mut self) {
+        self.git_commit_input = Some(String::new());
+    }
+
+    // --- Editor ---
+
+    fn current_line_len(&self) -> usize {
+        self.lines[self.cursor.0].chars().count()
+    }
+
+    fn full_text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    fn set_full_text(&mut self, text: &str) {
+        self.lines = if text.is_empty() {
+            vec![String::new()]
+        } else {
+            text.split('\n').map(String::from).collect()
+        };
+    }
+
+    fn pos_to_char_offset(&self, row: usize, col: usize) -> usize {
+        let mut offset = 0;
+        for line in &self.lines[..row] {
+            offset += line.chars().count() + 1;
+        }
+        offset + col
+    }
+
+    fn char_offset_to_pos(&self, offset: usize) -> (usize, usize) {
+        let mut remaining = offset;
+        for (row, line) in self.lines.iter().enumerate() {
+            let len = line.chars().count();
+            if remaining <= len {
+                return (row, remaining);
+            }
+            remaining -= len + 1;
+        }
+        (self.lines.len() - 1, self.lines.last().map_or(0, |l| l.chars().count()))
+    }
+
+    /// Inserts `text` (which may contain newlines) at `(row, col)` and returns the cursor
+    /// position just past it.
+    fn apply_insert(&mut self, row: usize, col: usize, text: &str) -> (usize, usize) {
+        let offset = self.pos_to_char_offset(row, col);
+        let mut full = self.full_text();
+        let byte_idx = char_to_byte(&full, offset);
+        full.insert_str(byte_idx, text);
+        self.set_full_text(&full);
+        self.char_offset_to_pos(offset + text.chars().count())
+    }
+
+    /// Removes `char_len` characters starting at `(row, col)`, returning the removed text.
+    fn apply_delete(&mut self, row: usize, col: usize, char_len: usize) -> String {
+        let offset = self.pos_to_char_offset(row, col);
+        let mut full = self.full_text();
+        let start = char_to_byte(&full, offset);
+        let end = char_to_byte(&full, offset + char_len);
+        let removed = full[start..end].to_string();
+        full.replace_range(start..end, "");
+        self.set_full_text(&full);
+        self.cursor = self.char_offset_to_pos(offset);
+        removed
+    }
+
+    fn push_undo(&mut self, op: EditOp) {
+        self.redo.clear();
+        self.undo.push(op);
+        if self.undo.len() > MAX_UNDO {
+            self.undo.remove(0);
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let (row, col) = self.cursor;
+        self.cursor = self.apply_insert(row, col, &c.to_string());
+        self.dirty = true;
+
+        if self.coalescing {
+            if let Some(EditOp::Delete { row: r, col: c0, text }) = self.undo.last_mut() {
+                if *r == row && *c0 + text.chars().count() == col {
+                    text.push(c);
+                    self.redo.clear();
+                    return;
+                }
+            }
+        }
+        self.push_undo(EditOp::Delete { row, col, text: c.to_string() });
+        self.coalescing = true;
+    }
+
+    pub fn insert_newline(&mut self) {
+        let (row, col) = self.cursor;
+        self.cursor = self.apply_insert(row, col, "\n");
+        self.dirty = true;
+        self.push_undo(EditOp::Delete { row, col, text: "\n".to_string() });
+        self.coalescing = false;
+    }
+
+    pub fn backspace(&mut self) {
+        let (row, col) = self.cursor;
+        if col == 0 && row == 0 {
+            return;
+        }
+        let (del_row, del_col) = if col > 0 {
+            (row, col - 1)
+        } else {
+            (row - 1, self.lines[row - 1].chars().count())
+        };
+        let removed = self.apply_delete(del_row, del_col, 1);
+        self.dirty = true;
+        self.push_undo(EditOp::Insert { row: del_row, col: del_col, text: removed });
+        self.coalescing = false;
+    }
+
+    pub fn delete_forward(&mut self) {
+        let (row, col) = self.cursor;
+        if col >= self.current_line_len() && row + 1 >= self.lines.len() {
+            return;
+        }
+        let removed = self.apply_delete(row, col, 1);
+        self.cursor = (row, col);
+        self.dirty = true;
+        self.push_undo(EditOp::Insert { row, col, text: removed });
+        self.coalescing = false;
+    }
+
+    /// Applies an `EditOp` to the buffer (used by both undo and redo).
+    fn apply_edit_op(&mut self, op: &EditOp) {
+        match op {
+            EditOp::Insert { row, col, text } => {
+                self.cursor = self.apply_insert(*row, *col, text);
+            }
+            EditOp::Delete { row, col, text } => {
+                self.apply_delete(*row, *col, text.chars().count());
+                self.cursor = (*row, *col);
+            }
+        }
+        self.dirty = true;
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(op) = self.undo.pop() {
+            let redo_op = op.inverse();
+            self.apply_edit_op(&op);
+            self.redo.push(redo_op);
+            self.coalescing = false;
+        }
+    }
+
+    pub fn redo_edit(&mut self) {
+        if let Some(op) = self.redo.pop() {
+            let undo_op = op.inverse();
+            self.apply_edit_op(&op);
+            self.undo.push(undo_op);
+            self.coalescing = false;
+        }
+    }
+
+    pub fn move_cursor_up(&mut self) {
+        if self.cursor.0 > 0 {
+            self.cursor.0 -= 1;
+            self.cursor.1 = self.cursor.1.min(self.current_line_len());
+        }
+        self.coalescing = false;
+    }
+
+    pub fn move_cursor_down(&mut self) {
+        if self.cursor.0 + 1 < self.lines.len() {
+            self.cursor.0 += 1;
+            self.cursor.1 = self.cursor.1.min(self.current_line_len());
+        }
+        self.coalescing = false;
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        if self.cursor.1 > 0 {
+            self.cursor.1 -= 1;
+        } else if self.cursor.0 > 0 {
+            self.cursor.0 -= 1;
+            self.cursor.1 = self.current_line_len();
+        }
+        self.coalescing = false;
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        if self.cursor.1 < self.current_line_len() {
+            self.cursor.1 += 1;
+        } else if self.cursor.0 + 1 < self.lines.len() {
+            self.cursor.0 += 1;
+            self.cursor.1 = 0;
+        }
+        self.coalescing = false;
+    }
+
+    /// Keeps `editor_scroll` such that the cursor row stays within a viewport
+    /// of `height` visible lines.
+    pub fn scroll_editor_to_cursor(&mut self, height: usize) {
+        if height == 0 {
+            return;
+        }
+        if self.cursor.0 < self.editor_scroll {
+            self.editor_scroll = self.cursor.0;
+        } else if self.cursor.0 >= self.editor_scroll + height {
+            self.editor_scroll = self.cursor.0 + 1 - height;
+        }
+    }
+}
+
+fn char_to_byte(line: &str, char_idx: usize) -> usize {
+    line.char_indices()
+        .nth(char_idx)
+        .map(|(i, _)| i)
+        .unwrap_or(line.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app(lines: &[&str]) -> App {
+        let keymap = Keymap::load(&PathBuf::new());
+        let help_text = keymap.help_text();
+        App {
+            project_path: PathBuf::new(),
+            status: "OFFLINE".to_string(),
+            logs: Vec::new(),
+            files: Vec::new(),
+            file_list_state: ListState::default(),
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+            cursor: (0, 0),
+            editor_scroll: 0,
+            dirty: false,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            coalescing: false,
+            current_file: None,
+            self_write: None,
+            highlighter: Highlighter::new(),
+            highlight_enabled: false,
+            keymap,
+            help_text,
+            git_entries: Vec::new(),
+            git_list_state: ListState::default(),
+            git_diff: Vec::new(),
+            git_diff_scroll: 0,
+            git_commit_input: None,
+            focus: Focus::Editor,
+            should_quit: false,
+        }
+    }
+
+    #[test]
+    fn pos_char_offset_round_trips_through_multibyte_lines() {
+        let app = test_app(&["héllo", "wörld"]);
+        let offset = app.pos_to_char_offset(1, 3);
+        assert_eq!(offset, "héllo".chars().count() + 1 + 3);
+        assert_eq!(app.char_offset_to_pos(offset), (1, 3));
+    }
+
+    #[test]
+    fn pos_char_offset_accounts_for_newlines_between_lines() {
+        let app = test_app(&["abc", "de"]);
+        assert_eq!(app.pos_to_char_offset(0, 0), 0);
+        assert_eq!(app.pos_to_char_offset(1, 0), 4);
+        assert_eq!(app.char_offset_to_pos(4), (1, 0));
+    }
+
+    #[test]
+    fn insert_char_coalesces_consecutive_typing_into_one_undo_group() {
+        let mut app = test_app(&[""]);
+        app.insert_char('a');
+        app.insert_char('b');
+        app.insert_char('c');
+        assert_eq!(app.lines[0], "abc");
+        assert_eq!(app.undo.len(), 1);
+    }
+
+    #[test]
+    fn moving_the_cursor_breaks_coalescing() {
+        let mut app = test_app(&[""]);
+        app.insert_char('a');
+        app.move_cursor_left();
+        app.insert_char('b');
+        assert_eq!(app.undo.len(), 2);
+    }
+
+    #[test]
+    fn undo_then_redo_restores_inserted_text() {
+        let mut app = test_app(&[""]);
+        app.insert_char('a');
+        app.insert_char('b');
+        assert_eq!(app.lines[0], "ab");
+
+        app.undo();
+        assert_eq!(app.lines[0], "");
+
+        app.redo_edit();
+        assert_eq!(app.lines[0], "ab");
+    }
+
+    #[test]
+    fn undo_reverses_a_newline_split() {
+        let mut app = test_app(&["abcd"]);
+        app.cursor = (0, 2);
+        app.insert_newline();
+        assert_eq!(app.lines, vec!["ab".to_string(), "cd".to_string()]);
+
+        app.undo();
+        assert_eq!(app.lines, vec!["abcd".to_string()]);
+    }
+
+    #[test]
+    fn edit_op_inverse_round_trips() {
+        let insert = EditOp::Insert { row: 0, col: 1, text: "x".to_string() };
+        let delete = insert.inverse();
+        match delete {
+            EditOp::Delete { row, col, text } => {
+                assert_eq!((row, col, text.as_str()), (0, 1, "x"));
+            }
+            _ => panic!("expected Delete"),
+        }
+        match delete.inverse() {
+            EditOp::Insert { row, col, text } => assert_eq!((row, col, text.as_str()), (0, 1, "x")),
+            _ => panic!("expected Insert"),
+        }
+    }
+}
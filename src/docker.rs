@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use bollard::container::{
+    Config, CreateContainerOptions, LogsOptions, RemoveContainerOptions, StartContainerOptions, StopContainerOptions,
+};
+use bollard::image::CreateImageOptions;
+use bollard::models::{HostConfig, PortBinding};
+use bollard::Docker;
+use futures::StreamExt;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::event::Event;
+
+const CONTAINER_NAME: &str = "edioc-studio-docusaurus";
+const DEFAULT_IMAGE: &str = "node:20-alpine";
+const CONTAINER_PORT: &str = "3000/tcp";
+/// Overrides the dev-server image without a code change, e.g. for a project that needs a
+/// different Node version.
+const IMAGE_ENV_VAR: &str = "EDIOC_DOCKER_IMAGE";
+
+/// Owns the lifecycle of the Docusaurus dev-server container: create/start/stop/remove,
+/// plus streaming its logs into the shared event channel.
+pub struct DockerManager {
+    docker: Docker,
+    image: String,
+    host_port: u16,
+    project_path: PathBuf,
+    container_id: Option<String>,
+}
+
+impl DockerManager {
+    /// Connects to the local Docker daemon. The dev-server image defaults to
+    /// `node:20-alpine` but can be overridden by setting `EDIOC_DOCKER_IMAGE`.
+    pub fn connect(project_path: PathBuf) -> Result<Self, bollard::errors::Error> {
+        let docker = Docker::connect_with_local_defaults()?;
+        let image = std::env::var(IMAGE_ENV_VAR).unwrap_or_else(|_| DEFAULT_IMAGE.to_string());
+        Ok(DockerManager {
+            docker,
+            image,
+            host_port: 3000,
+            project_path,
+            container_id: None,
+        })
+    }
+
+    /// Creates (if needed) and starts the dev-server container, then begins streaming its
+    /// logs into `tx` as `Event::DockerLog`. Reports the new status via `Event::DockerStatus`.
+    pub async fn start(&mut self, tx: UnboundedSender<Event>) -> Result<(), bollard::errors::Error> {
+        if self.reuse_existing_container().await? {
+            self.stream_logs(tx.clone());
+            self.report_status(&tx).await;
+            return Ok(());
+        }
+
+        self.pull_image(&tx).await;
+
+        let mut port_bindings = HashMap::new();
+        port_bindings.insert(
+            CONTAINER_PORT.to_string(),
+            Some(vec![PortBinding {
+                host_ip: Some("0.0.0.0".to_string()),
+                host_port: Some(self.host_port.to_string()),
+            }]),
+        );
+
+        let host_config = HostConfig {
+            port_bindings: Some(port_bindings),
+            binds: Some(vec![format!("{}:/app", self.project_path.display())]),
+            ..Default::default()
+        };
+
+        let mut exposed_ports = HashMap::new();
+        exposed_ports.insert(CONTAINER_PORT.to_string(), HashMap::new());
+
+        let config = Config {
+            image: Some(self.image.clone()),
+            working_dir: Some("/app".to_string()),
+            cmd: Some(vec!["npm".into(), "run".into(), "start".into(), "--".into(), "--host".into(), "0.0.0.0".into()]),
+            exposed_ports: Some(exposed_ports),
+            host_config: Some(host_config),
+            ..Default::default()
+        };
+
+        let options = CreateContainerOptions { name: CONTAINER_NAME, platform: None };
+        let container = self.docker.create_container(Some(options), config).await?;
+        self.container_id = Some(container.id.clone());
+
+        self.docker
+            .start_container(&container.id, None::<StartContainerOptions<String>>)
+            .await?;
+
+        self.stream_logs(tx.clone());
+        self.report_status(&tx).await;
+        Ok(())
+    }
+
+    /// Inspects the container's actual state and reports it, rather than assuming success.
+    async fn report_status(&self, tx: &UnboundedSender<Event>) {
+        let status = if self.is_running().await { "RUNNING" } else { "OFFLINE" };
+        let _ = tx.send(Event::DockerStatus(status.to_string()));
+    }
+
+    /// Looks for a container already using our fixed name, left over from a crashed or
+    /// killed prior run (a clean quit removes it via `stop_and_remove`, but nothing else
+    /// does). A running one is adopted as-is; a stopped one is removed so the `create_container`
+    /// call below doesn't fail with a 409 name conflict. Returns `true` if an existing running
+    /// container was adopted and `start` can skip straight to streaming logs.
+    async fn reuse_existing_container(&mut self) -> Result<bool, bollard::errors::Error> {
+        let inspect = match self.docker.inspect_container(CONTAINER_NAME, None).await {
+            Ok(inspect) => inspect,
+            Err(_) => return Ok(false),
+        };
+        let id = inspect.id.unwrap_or_else(|| CONTAINER_NAME.to_string());
+        if inspect.state.and_then(|s| s.running).unwrap_or(false) {
+            self.container_id = Some(id);
+            return Ok(true);
+        }
+        self.docker
+            .remove_container(&id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+            .await?;
+        Ok(false)
+    }
+
+    /// Pulls `self.image` so `create_container` doesn't fail with "No such image" on a host
+    /// that has never run this dev-server image before.
+    async fn pull_image(&self, tx: &UnboundedSender<Event>) {
+        let _ = tx.send(Event::DockerLog(format!("Pulling image {}...", self.image)));
+        let options = CreateImageOptions { from_image: self.image.clone(), ..Default::default() };
+        let mut stream = self.docker.create_image(Some(options), None, None);
+        while let Some(result) = stream.next().await {
+            if let Err(err) = result {
+                let _ = tx.send(Event::DockerLog(format!("Image pull warning: {err}")));
+                break;
+            }
+        }
+    }
+
+    fn stream_logs(&self, tx: UnboundedSender<Event>) {
+        let docker = self.docker.clone();
+        let Some(id) = self.container_id.clone() else { return };
+        tokio::spawn(async move {
+            let options = LogsOptions::<String> {
+                follow: true,
+                stdout: true,
+                stderr: true,
+                ..Default::default()
+            };
+            let mut stream = docker.logs(&id, Some(options));
+            while let Some(Ok(chunk)) = stream.next().await {
+                for line in chunk.to_string().lines() {
+                    if tx.send(Event::DockerLog(line.to_string())).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    pub async fn stop(&mut self) -> Result<(), bollard::errors::Error> {
+        if let Some(id) = &self.container_id {
+            self.docker.stop_container(id, None::<StopContainerOptions>).await?;
+        }
+        Ok(())
+    }
+
+    /// Stops and removes the container, clearing our handle to it.
+    pub async fn stop_and_remove(&mut self) -> Result<(), bollard::errors::Error> {
+        self.stop().await?;
+        if let Some(id) = self.container_id.take() {
+            self.docker
+                .remove_container(&id, Some(RemoveContainerOptions { force: true, ..Default::default() }))
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn restart(&mut self, tx: UnboundedSender<Event>) -> Result<(), bollard::errors::Error> {
+        self.stop_and_remove().await?;
+        self.start(tx).await
+    }
+
+    pub async fn is_running(&self) -> bool {
+        let Some(id) = &self.container_id else { return false };
+        match self.docker.inspect_container(id, None).await {
+            Ok(inspect) => inspect.state.and_then(|s| s.running).unwrap_or(false),
+            Err(_) => false,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crossterm::event::{Event as CEvent, EventStream, KeyEvent};
+use futures::StreamExt;
+use tokio::sync::mpsc;
+
+use crate::git::GitEntry;
+
+/// Everything the main loop can react to. Unlike a synchronous poll loop, background
+/// subsystems (Docker, the file watcher, Git) can push their own variants in at any time.
+#[derive(Debug, Clone)]
+pub enum Event {
+    Key(KeyEvent),
+    Resize(u16, u16),
+    Tick,
+    DockerLog(String),
+    DockerStatus(String),
+    FileChanged(PathBuf),
+    FileCreated(PathBuf),
+    FileRemoved(PathBuf),
+    GitStatus(Vec<GitEntry>),
+    GitDiff(String),
+    GitCommitResult(Result<(), String>),
+}
+
+/// Owns the receiving end of the event channel plus a cloneable sender that background
+/// tasks (Docker streaming, the file watcher, ...) can use to post their own events.
+pub struct EventChannel {
+    pub rx: mpsc::UnboundedReceiver<Event>,
+    pub tx: mpsc::UnboundedSender<Event>,
+}
+
+impl EventChannel {
+    pub fn new(tick_rate: Duration) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        spawn_input_forwarder(tx.clone());
+        spawn_ticker(tx.clone(), tick_rate);
+        EventChannel { rx, tx }
+    }
+}
+
+fn spawn_input_forwarder(tx: mpsc::UnboundedSender<Event>) {
+    tokio::spawn(async move {
+        let mut stream = EventStream::new();
+        while let Some(Ok(ev)) = stream.next().await {
+            let mapped = match ev {
+                CEvent::Key(key) => Some(Event::Key(key)),
+                CEvent::Resize(w, h) => Some(Event::Resize(w, h)),
+                _ => None,
+            };
+            if let Some(mapped) = mapped {
+                if tx.send(mapped).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+fn spawn_ticker(tx: mpsc::UnboundedSender<Event>, tick_rate: Duration) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tick_rate);
+        loop {
+            interval.tick().await;
+            if tx.send(Event::Tick).is_err() {
+                break;
+            }
+        }
+    });
+}
@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::event::Event;
+
+/// One line of `git status --porcelain`, parsed into staged/unstaged/untracked flags.
+#[derive(Debug, Clone)]
+pub struct GitEntry {
+    pub path: String,
+    pub staged: bool,
+    pub unstaged: bool,
+    pub untracked: bool,
+}
+
+/// Parses `-z`-delimited (NUL-separated, unquoted) `git status --porcelain` output. Renamed
+/// entries carry an extra NUL-terminated field for the original path, which we just skip
+/// since staging/unstaging operates on the current path.
+fn parse_porcelain(output: &str) -> Vec<GitEntry> {
+    let mut entries = Vec::new();
+    let mut tokens = output.split('\0').filter(|s| !s.is_empty());
+    while let Some(record) = tokens.next() {
+        if record.len() < 4 {
+            continue;
+        }
+        let status = &record[0..2];
+        let path = record[3..].to_string();
+        if status.starts_with('R') || status.starts_with('C') {
+            tokens.next();
+        }
+        let untracked = status == "??";
+        let staged = !untracked && status.chars().next().is_some_and(|c| c != ' ');
+        let unstaged = !untracked && status.chars().nth(1).is_some_and(|c| c != ' ');
+        entries.push(GitEntry { path, staged, unstaged, untracked });
+    }
+    entries
+}
+
+/// Runs `git status --porcelain -z` off the UI thread and reports parsed entries via `tx`.
+/// `-z` keeps paths unquoted and NUL-separated so names with spaces or non-ASCII characters
+/// (which `git status` would otherwise C-quote) round-trip correctly into `git add`/`restore`.
+pub fn spawn_status(repo: PathBuf, tx: UnboundedSender<Event>) {
+    tokio::spawn(async move {
+        let output = tokio::task::spawn_blocking(move || {
+            Command::new("git").arg("-C").arg(&repo).arg("status").arg("--porcelain").arg("-z").output()
+        })
+        .await;
+        if let Ok(Ok(out)) = output {
+            let entries = parse_porcelain(&String::from_utf8_lossy(&out.stdout));
+            let _ = tx.send(Event::GitStatus(entries));
+        }
+    });
+}
+
+/// Runs a unified diff for `path` off the UI thread. Plain (uncolored) output is requested
+/// since the UI colorizes lines itself by inspecting their `+`/`-`/`@@` prefix. Untracked
+/// files have nothing in `HEAD` to diff against, so they're diffed against `/dev/null`
+/// instead of silently producing empty output.
+pub fn spawn_diff(repo: PathBuf, path: String, untracked: bool, tx: UnboundedSender<Event>) {
+    tokio::spawn(async move {
+        let output = tokio::task::spawn_blocking(move || {
+            let mut cmd = Command::new("git");
+            cmd.arg("-C").arg(&repo).arg("diff").arg("--color=never");
+            if untracked {
+                cmd.arg("--no-index").arg("--").arg("/dev/null").arg(&path);
+            } else {
+                cmd.arg("HEAD").arg("--").arg(&path);
+            }
+            cmd.output()
+        })
+        .await;
+        if let Ok(Ok(out)) = output {
+            let _ = tx.send(Event::GitDiff(String::from_utf8_lossy(&out.stdout).to_string()));
+        }
+    });
+}
+
+/// Stages `path`, then refreshes status.
+pub fn spawn_stage(repo: PathBuf, path: String, tx: UnboundedSender<Event>) {
+    let refresh_repo = repo.clone();
+    let refresh_tx = tx.clone();
+    tokio::spawn(async move {
+        let _ = tokio::task::spawn_blocking(move || Command::new("git").arg("-C").arg(&repo).arg("add").arg("--").arg(&path).status()).await;
+        spawn_status(refresh_repo, refresh_tx);
+    });
+}
+
+/// Unstages `path`, then refreshes status.
+pub fn spawn_unstage(repo: PathBuf, path: String, tx: UnboundedSender<Event>) {
+    let refresh_repo = repo.clone();
+    let refresh_tx = tx.clone();
+    tokio::spawn(async move {
+        let _ = tokio::task::spawn_blocking(move || {
+            Command::new("git").arg("-C").arg(&repo).arg("restore").arg("--staged").arg("--").arg(&path).status()
+        })
+        .await;
+        spawn_status(refresh_repo, refresh_tx);
+    });
+}
+
+/// Commits the currently staged changes with `message`, reports success/failure, then
+/// refreshes status.
+pub fn spawn_commit(repo: PathBuf, message: String, tx: UnboundedSender<Event>) {
+    let refresh_repo = repo.clone();
+    let refresh_tx = tx.clone();
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || {
+            Command::new("git").arg("-C").arg(&repo).arg("commit").arg("-m").arg(&message).output()
+        })
+        .await;
+        let outcome = match result {
+            Ok(Ok(out)) if out.status.success() => Ok(()),
+            Ok(Ok(out)) => Err(String::from_utf8_lossy(&out.stderr).trim().to_string()),
+            _ => Err("failed to run git commit".to_string()),
+        };
+        let _ = tx.send(Event::GitCommitResult(outcome));
+        spawn_status(refresh_repo, refresh_tx);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_staged_modified_and_untracked_entries() {
+        let entries = parse_porcelain("M  staged.md\0 M unstaged.md\0?? new.md\0");
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].path, "staged.md");
+        assert!(entries[0].staged && !entries[0].unstaged && !entries[0].untracked);
+
+        assert_eq!(entries[1].path, "unstaged.md");
+        assert!(!entries[1].staged && entries[1].unstaged && !entries[1].untracked);
+
+        assert_eq!(entries[2].path, "new.md");
+        assert!(entries[2].untracked && !entries[2].staged && !entries[2].unstaged);
+    }
+
+    #[test]
+    fn parses_staged_and_unstaged_at_once() {
+        let entries = parse_porcelain("MM both.md\0");
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].staged && entries[0].unstaged);
+    }
+
+    #[test]
+    fn keeps_the_unquoted_path_for_names_with_spaces() {
+        let entries = parse_porcelain("?? My Page.md\0");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path, "My Page.md");
+    }
+
+    #[test]
+    fn follows_the_new_path_and_skips_the_origin_field_for_renames() {
+        let entries = parse_porcelain("R  new.md\0old.md\0?? trailing.md\0");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, "new.md");
+        assert_eq!(entries[1].path, "trailing.md");
+    }
+
+    #[test]
+    fn ignores_blank_input() {
+        assert!(parse_porcelain("\0").is_empty());
+        assert!(parse_porcelain("").is_empty());
+    }
+}
@@ -0,0 +1,68 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, Style as SynStyle, Theme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+const THEME_NAME: &str = "base16-ocean.dark";
+
+/// Holds the loaded Markdown syntax and theme so they're parsed once per process instead
+/// of on every redraw.
+pub struct Highlighter {
+    syntax_set: SyntaxSet,
+    theme: Theme,
+}
+
+impl Highlighter {
+    pub fn new() -> Self {
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+        let theme = theme_set
+            .themes
+            .get(THEME_NAME)
+            .cloned()
+            .unwrap_or_else(|| theme_set.themes.values().next().cloned().expect("bundled themes are never empty"));
+        Highlighter { syntax_set, theme }
+    }
+
+    fn markdown_syntax(&self) -> &SyntaxReference {
+        self.syntax_set
+            .find_syntax_by_extension("md")
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
+    }
+
+    /// Highlights a contiguous run of lines (typically just the visible editor viewport),
+    /// returning one styled `Line` per input line.
+    pub fn highlight_lines(&self, lines: &[String]) -> Vec<Line<'static>> {
+        let mut highlighter = HighlightLines::new(self.markdown_syntax(), &self.theme);
+        lines
+            .iter()
+            .map(|line| {
+                let mut with_newline = line.clone();
+                with_newline.push('\n');
+                let ranges = highlighter
+                    .highlight_line(&with_newline, &self.syntax_set)
+                    .unwrap_or_default();
+                let spans = ranges
+                    .into_iter()
+                    .map(|(style, text)| Span::styled(text.trim_end_matches('\n').to_string(), to_ratatui_style(style)))
+                    .collect::<Vec<_>>();
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+fn to_ratatui_style(style: SynStyle) -> Style {
+    let mut out = Style::default().fg(Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b));
+    if style.font_style.contains(FontStyle::BOLD) {
+        out = out.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        out = out.add_modifier(Modifier::ITALIC);
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        out = out.add_modifier(Modifier::UNDERLINED);
+    }
+    out
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+
+pub const CONFIG_FILE_NAME: &str = "edioc-studio.toml";
+
+/// Named action an App method carries out; key bindings map to these instead of the UI
+/// matching on literal `KeyCode`s directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    SwitchFocus,
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Switches to the Git panel (status/stage/diff/commit).
+    OpenGit,
+    /// Opens the commit message prompt while the Git panel is focused.
+    GitCommit,
+    Save,
+    RunDocker,
+    StopDocker,
+    ToggleHighlight,
+    Undo,
+    Redo,
+    /// Enter: splits a line in the editor, toggles stage in the Git panel.
+    Confirm,
+    Backspace,
+    DeleteForward,
+}
+
+impl Action {
+    fn from_name(name: &str) -> Option<Action> {
+        Some(match name {
+            "quit" => Action::Quit,
+            "switch_focus" => Action::SwitchFocus,
+            "up" => Action::Up,
+            "down" => Action::Down,
+            "left" => Action::Left,
+            "right" => Action::Right,
+            "open_git" => Action::OpenGit,
+            "git_commit" => Action::GitCommit,
+            "save" => Action::Save,
+            "run_docker" => Action::RunDocker,
+            "stop_docker" => Action::StopDocker,
+            "toggle_highlight" => Action::ToggleHighlight,
+            "undo" => Action::Undo,
+            "redo" => Action::Redo,
+            "confirm" => Action::Confirm,
+            "backspace" => Action::Backspace,
+            "delete_forward" => Action::DeleteForward,
+            _ => return None,
+        })
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::SwitchFocus => "Switch Focus",
+            Action::Up | Action::Down | Action::Left | Action::Right => "Move",
+            Action::OpenGit => "Git Panel",
+            Action::GitCommit => "Commit",
+            Action::Save => "Save",
+            Action::RunDocker => "Run/Restart Docker",
+            Action::StopDocker => "Stop Docker",
+            Action::ToggleHighlight => "Toggle Highlight",
+            Action::Undo => "Undo",
+            Action::Redo => "Redo",
+            Action::Confirm => "Confirm",
+            Action::Backspace => "Backspace",
+            Action::DeleteForward => "Delete",
+        }
+    }
+}
+
+/// Order and selection of actions shown in the Help Bar; editing/navigation keys are
+/// self-explanatory and would just add noise.
+const HELP_ACTIONS: &[Action] = &[
+    Action::Quit,
+    Action::SwitchFocus,
+    Action::RunDocker,
+    Action::StopDocker,
+    Action::OpenGit,
+    Action::ToggleHighlight,
+    Action::Save,
+    Action::Undo,
+    Action::Redo,
+];
+
+#[derive(Deserialize, Default)]
+struct RawConfig {
+    #[serde(default)]
+    keymap: HashMap<String, String>,
+}
+
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl Keymap {
+    /// Loads `edioc-studio.toml` from `project_path`, falling back to the XDG config dir,
+    /// falling back in turn to the built-in defaults when neither exists or fails to parse.
+    pub fn load(project_path: &Path) -> Keymap {
+        let mut bindings = default_bindings();
+
+        let raw = config_path(project_path)
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| toml::from_str::<RawConfig>(&contents).ok())
+            .unwrap_or_default();
+
+        for (name, spec) in raw.keymap {
+            if let (Some(action), Some(key)) = (Action::from_name(&name), parse_key(&spec)) {
+                bindings.insert(key, action);
+            }
+        }
+
+        Keymap { bindings }
+    }
+
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+
+    /// Renders the Help Bar text from whatever bindings are actually active.
+    pub fn help_text(&self) -> String {
+        let mut parts = Vec::new();
+        for action in HELP_ACTIONS {
+            if let Some((key, _)) = self.bindings.iter().find(|(_, a)| *a == action) {
+                parts.push(format!("[{}]{}", format_key(*key), action.label()));
+            }
+        }
+        format!(" {} ", parts.join(" | "))
+    }
+}
+
+fn config_path(project_path: &Path) -> Option<PathBuf> {
+    let project_config = project_path.join(CONFIG_FILE_NAME);
+    if project_config.exists() {
+        return Some(project_config);
+    }
+    let xdg_config = dirs::config_dir()?.join("edioc-studio").join(CONFIG_FILE_NAME);
+    if xdg_config.exists() {
+        return Some(xdg_config);
+    }
+    None
+}
+
+fn default_bindings() -> HashMap<(KeyCode, KeyModifiers), Action> {
+    let mut m = HashMap::new();
+    m.insert((KeyCode::Char('q'), KeyModifiers::NONE), Action::Quit);
+    m.insert((KeyCode::Tab, KeyModifiers::NONE), Action::SwitchFocus);
+    m.insert((KeyCode::Up, KeyModifiers::NONE), Action::Up);
+    m.insert((KeyCode::Down, KeyModifiers::NONE), Action::Down);
+    m.insert((KeyCode::Left, KeyModifiers::NONE), Action::Left);
+    m.insert((KeyCode::Right, KeyModifiers::NONE), Action::Right);
+    m.insert((KeyCode::Char('g'), KeyModifiers::NONE), Action::OpenGit);
+    m.insert((KeyCode::Char('c'), KeyModifiers::NONE), Action::GitCommit);
+    m.insert((KeyCode::Char('s'), KeyModifiers::CONTROL), Action::Save);
+    m.insert((KeyCode::Char('r'), KeyModifiers::NONE), Action::RunDocker);
+    m.insert((KeyCode::Char('x'), KeyModifiers::NONE), Action::StopDocker);
+    m.insert((KeyCode::Char('p'), KeyModifiers::NONE), Action::ToggleHighlight);
+    m.insert((KeyCode::Char('z'), KeyModifiers::CONTROL), Action::Undo);
+    m.insert((KeyCode::Char('y'), KeyModifiers::CONTROL), Action::Redo);
+    m.insert((KeyCode::Enter, KeyModifiers::NONE), Action::Confirm);
+    m.insert((KeyCode::Backspace, KeyModifiers::NONE), Action::Backspace);
+    m.insert((KeyCode::Delete, KeyModifiers::NONE), Action::DeleteForward);
+    m
+}
+
+/// Parses specs like `"ctrl+s"`, `"q"`, `"Tab"` into a `(KeyCode, KeyModifiers)` pair.
+fn parse_key(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let parts: Vec<&str> = spec.split('+').collect();
+    let (mods, key) = parts.split_at(parts.len().saturating_sub(1));
+    let mut modifiers = KeyModifiers::NONE;
+    for m in mods {
+        match m.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            _ => return None,
+        }
+    }
+    let code = parse_keycode(key.first()?)?;
+    Some((code, modifiers))
+}
+
+fn parse_keycode(spec: &str) -> Option<KeyCode> {
+    Some(match spec.to_ascii_lowercase().as_str() {
+        "tab" => KeyCode::Tab,
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "space" => KeyCode::Char(' '),
+        other if other.chars().count() == 1 => KeyCode::Char(other.chars().next().unwrap()),
+        _ => return None,
+    })
+}
+
+fn format_key((code, modifiers): (KeyCode, KeyModifiers)) -> String {
+    let mut prefix = String::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        prefix.push_str("Ctrl+");
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        prefix.push_str("Alt+");
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        prefix.push_str("Shift+");
+    }
+    let key = match code {
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        other => format!("{:?}", other),
+    };
+    format!("{}{}", prefix, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_single_letter() {
+        assert_eq!(parse_key("q"), Some((KeyCode::Char('q'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn parses_a_single_modifier() {
+        assert_eq!(parse_key("ctrl+s"), Some((KeyCode::Char('s'), KeyModifiers::CONTROL)));
+    }
+
+    #[test]
+    fn parses_stacked_modifiers_case_insensitively() {
+        assert_eq!(parse_key("Ctrl+Shift+z"), Some((KeyCode::Char('z'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)));
+    }
+
+    #[test]
+    fn parses_named_keys() {
+        assert_eq!(parse_key("tab"), Some((KeyCode::Tab, KeyModifiers::NONE)));
+        assert_eq!(parse_key("ctrl+enter"), Some((KeyCode::Enter, KeyModifiers::CONTROL)));
+        assert_eq!(parse_key("space"), Some((KeyCode::Char(' '), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn rejects_an_unknown_modifier() {
+        assert_eq!(parse_key("meta+s"), None);
+    }
+
+    #[test]
+    fn rejects_an_unknown_key_name() {
+        assert_eq!(parse_keycode("pagedown"), None);
+    }
+}
@@ -1,97 +1,29 @@
-use bollard::Docker;
+mod app;
+mod docker;
+mod event;
+mod git;
+mod highlight;
+mod keymap;
+mod ui;
+mod watcher;
+
+use std::io;
+use std::time::Duration;
+
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{prelude::*, widgets::*};
-use std::fs;
-use std::io::{self, Write};
-use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
-
-// --- Data Structures ---
-
-#[derive(PartialEq)]
-enum Focus {
-    FileList,
-    Editor,
-    Log,
-}
+use ratatui::prelude::*;
 
-struct App {
-    project_path: PathBuf,
-    status: String,
-    logs: Vec<String>,
-    files: Vec<PathBuf>,
-    file_list_state: ListState,
-    current_content: String,
-    focus: Focus,
-    should_quit: bool,
-}
+use app::{App, Focus};
+use docker::DockerManager;
+use event::{Event, EventChannel};
+use keymap::Action;
+use ui::ui;
 
-impl App {
-    fn new() -> io::Result<App> {
-        let path = std::env::current_dir()?;
-        let mut app = App {
-            project_path: path.clone(),
-            status: "OFFLINE".to_string(),
-            logs: vec!["[System] Manager started.".into()],
-            files: vec![],
-            file_list_state: ListState::default(),
-            current_content: String::new(),
-            focus: Focus::FileList,
-            should_quit: false,
-        };
-        app.refresh_files();
-        Ok(app)
-    }
-
-    fn refresh_files(&mut self) {
-        self.files = WalkDir::new(&self.project_path)
-            .max_depth(3)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().map_or(false, |ext| ext == "md" || ext == "mdx"))
-            .map(|e| e.path().to_path_buf())
-            .collect();
-        if !self.files.is_empty() && self.file_list_state.selected().is_none() {
-            self.file_list_state.select(Some(0));
-        }
-    }
-
-    fn load_selected_file(&mut self) {
-        if let Some(i) = self.file_list_state.selected() {
-            if let Ok(content) = fs::read_to_string(&self.files[i]) {
-                self.current_content = content;
-                self.logs.push(format!("[File] Loaded {}", self.files[i].display()));
-            }
-        }
-    }
-
-    fn save_current_file(&mut self) {
-        if let Some(i) = self.file_list_state.selected() {
-            if fs::write(&self.files[i], &self.current_content).is_ok() {
-                self.logs.push(format!("[File] Saved {}", self.files[i].display()));
-            }
-        }
-    }
-
-    fn git_status(&mut self) {
-        let output = std::process::Command::new("git")
-            .arg("status")
-            .arg("--short")
-            .output();
-        
-        if let Ok(out) = output {
-            let status = String::from_utf8_lossy(&out.stdout);
-            self.logs.push("-- Git Status --".to_string());
-            for line in status.lines() {
-                self.logs.push(line.to_string());
-            }
-        }
-    }
-}
+const TICK_RATE: Duration = Duration::from_millis(100);
 
 // --- Main Loop ---
 
@@ -99,117 +31,195 @@ impl App {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, crossterm::event::EnableMouseCapture, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new()?;
+    let mut events = EventChannel::new(TICK_RATE);
+    let mut docker: Option<DockerManager> = None;
+    // Keep the watcher alive for the process lifetime; dropping it stops watching.
+    let _watcher = watcher::spawn_watcher(app.project_path.clone(), events.tx.clone());
 
     while !app.should_quit {
         terminal.draw(|f| ui(f, &mut app))?;
 
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+        match events.rx.recv().await {
+            Some(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                // The commit message prompt captures all typing until Enter/Esc, regardless
+                // of the active keymap.
+                if let Some(buffer) = app.git_commit_input.as_mut() {
                     match key.code {
-                        KeyCode::Char('q') => app.should_quit = true,
-                        KeyCode::Tab => {
-                            app.focus = match app.focus {
-                                Focus::FileList => Focus::Editor,
-                                Focus::Editor => Focus::Log,
-                                Focus::Log => Focus::FileList,
-                            };
-                        }
-                        // ファイルナビゲーション
-                        KeyCode::Up if app.focus == Focus::FileList => {
-                            let i = app.file_list_state.selected().unwrap_or(0);
-                            app.file_list_state.select(Some(i.saturating_sub(1)));
-                            app.load_selected_file();
-                        }
-                        KeyCode::Down if app.focus == Focus::FileList => {
-                            let i = app.file_list_state.selected().unwrap_or(0);
-                            app.file_list_state.select(Some((i + 1).min(app.files.len() - 1)));
-                            app.load_selected_file();
-                        }
-                        // Git操作ショートカット
-                        KeyCode::Char('g') => app.git_status(),
-                        // 保存ショートカット
-                        KeyCode::Char('s') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
-                            app.save_current_file();
+                        KeyCode::Enter => {
+                            let message = buffer.clone();
+                            app.git_commit_input = None;
+                            if !message.trim().is_empty() {
+                                git::spawn_commit(app.project_path.clone(), message, events.tx.clone());
+                            }
                         }
-                        // Docker起動 (Dummy logic for example)
-                        KeyCode::Char('r') => {
-                            app.status = "RUNNING".to_string();
-                            app.logs.push("[Docker] Container started on port 3000".into());
+                        KeyCode::Esc => app.git_commit_input = None,
+                        KeyCode::Backspace => {
+                            buffer.pop();
                         }
+                        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => buffer.push(c),
                         _ => {}
                     }
+                    continue;
+                }
+                // Plain typing always wins in the editor, regardless of what the active
+                // keymap binds that character to elsewhere.
+                if app.focus == Focus::Editor {
+                    if let KeyCode::Char(c) = key.code {
+                        if key.modifiers.difference(KeyModifiers::SHIFT).is_empty() {
+                            app.insert_char(c);
+                            continue;
+                        }
+                    }
                 }
+                if let Some(action) = app.keymap.resolve(key.code, key.modifiers) {
+                    dispatch(action, &mut app, &mut docker, &events.tx).await;
+                }
+            }
+            Some(Event::Key(_)) => {}
+            Some(Event::Resize(_, _)) | Some(Event::Tick) => {
+                // Nothing to do; the next loop iteration redraws with fresh sizes/state.
+            }
+            Some(Event::DockerLog(line)) => app.logs.push(format!("[Docker] {}", line)),
+            Some(Event::DockerStatus(status)) => app.status = status,
+            Some(Event::FileChanged(path)) => {
+                app.refresh_files();
+                app.reload_if_current(&path);
+            }
+            Some(Event::FileCreated(path)) => {
+                app.refresh_files();
+                app.logs.push(format!("[Watcher] New file: {}", path.display()));
             }
+            Some(Event::FileRemoved(path)) => {
+                app.refresh_files();
+                app.logs.push(format!("[Watcher] Removed: {}", path.display()));
+            }
+            Some(Event::GitStatus(entries)) => app.set_git_status(entries),
+            Some(Event::GitDiff(diff)) => app.set_git_diff(diff),
+            Some(Event::GitCommitResult(Ok(()))) => app.logs.push("[Git] Commit created.".into()),
+            Some(Event::GitCommitResult(Err(err))) => app.logs.push(format!("[Git] Commit failed: {err}")),
+            None => app.should_quit = true,
         }
     }
 
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, crossterm::event::DisableMouseCapture)?;
     Ok(())
 }
 
-// --- UI Rendering ---
-
-fn ui(f: &mut Frame, app: &mut App) {
-    let size = f.size();
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(10),   // Middle (Files + Editor)
-            Constraint::Length(8), // Footer (Logs)
-            Constraint::Length(1), // Help Bar
-        ])
-        .split(size);
-
-    // 1. Header
-    let status_color = if app.status == "RUNNING" { Color::Green } else { Color::Yellow };
-    let header = Paragraph::new(format!(" Docusaurus Manager | Status: {} | Path: {}", app.status, app.project_path.display()))
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(status_color)))
-        .style(Style::default().fg(Color::White).bold());
-    f.render_widget(header, chunks[0]);
-
-    // 2. Middle Area (Horizontal)
-    let body_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
-        .split(chunks[1]);
+async fn dispatch(action: Action, app: &mut App, docker: &mut Option<DockerManager>, tx: &tokio::sync::mpsc::UnboundedSender<Event>) {
+    match action {
+        Action::Quit if app.focus != Focus::Editor => {
+            stop_docker(docker, app).await;
+            app.should_quit = true;
+        }
+        Action::SwitchFocus => {
+            app.focus = match app.focus {
+                Focus::FileList => Focus::Editor,
+                Focus::Editor => Focus::Log,
+                Focus::Log => Focus::Git,
+                Focus::Git => Focus::FileList,
+            };
+        }
+        // ファイルナビゲーション / エディタのカーソル移動 / Gitパネルの操作
+        Action::Up if app.focus == Focus::FileList => {
+            let i = app.file_list_state.selected().unwrap_or(0);
+            app.file_list_state.select(Some(i.saturating_sub(1)));
+            app.load_selected_file();
+        }
+        Action::Down if app.focus == Focus::FileList => {
+            let i = app.file_list_state.selected().unwrap_or(0);
+            app.file_list_state.select(Some((i + 1).min(app.files.len() - 1)));
+            app.load_selected_file();
+        }
+        Action::Up if app.focus == Focus::Editor => app.move_cursor_up(),
+        Action::Down if app.focus == Focus::Editor => app.move_cursor_down(),
+        Action::Left if app.focus == Focus::Editor => app.move_cursor_left(),
+        Action::Right if app.focus == Focus::Editor => app.move_cursor_right(),
+        Action::Up if app.focus == Focus::Git => {
+            app.git_select_up();
+            refresh_git_diff(app, tx);
+        }
+        Action::Down if app.focus == Focus::Git => {
+            app.git_select_down();
+            refresh_git_diff(app, tx);
+        }
+        Action::Left if app.focus == Focus::Git => app.git_scroll_diff_up(),
+        Action::Right if app.focus == Focus::Git => app.git_scroll_diff_down(),
+        // Gitパネルを開く
+        Action::OpenGit => {
+            app.focus = Focus::Git;
+            git::spawn_status(app.project_path.clone(), tx.clone());
+        }
+        Action::GitCommit if app.focus == Focus::Git => app.open_commit_prompt(),
+        // Markdownシンタックスハイライトの切り替え
+        Action::ToggleHighlight if app.focus != Focus::Editor => app.toggle_highlight(),
+        // 保存ショートカット
+        Action::Save => app.save_current_file(),
+        // Docker操作
+        Action::RunDocker if app.focus != Focus::Editor => start_or_restart_docker(docker, app, tx).await,
+        Action::StopDocker if app.focus != Focus::Editor => stop_docker(docker, app).await,
+        // --- エディタ編集 ---
+        Action::Undo if app.focus == Focus::Editor => app.undo(),
+        Action::Redo if app.focus == Focus::Editor => app.redo_edit(),
+        Action::Confirm if app.focus == Focus::Editor => app.insert_newline(),
+        Action::Confirm if app.focus == Focus::Git => toggle_stage_selected(app, tx),
+        Action::Backspace if app.focus == Focus::Editor => app.backspace(),
+        Action::DeleteForward if app.focus == Focus::Editor => app.delete_forward(),
+        _ => {}
+    }
+}
 
-    // File List
-    let items: Vec<ListItem> = app.files
-        .iter()
-        .map(|p| {
-            let filename = p.file_name().unwrap().to_string_lossy();
-            ListItem::new(format!(" 📄 {}", filename))
-        })
-        .collect();
-    
-    let list_block = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title(" Files (Up/Down) "))
-        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White))
-        .highlight_symbol(">> ");
-    
-    f.render_stateful_widget(list_block, body_chunks[0], &mut app.file_list_state);
+fn refresh_git_diff(app: &App, tx: &tokio::sync::mpsc::UnboundedSender<Event>) {
+    let Some(i) = app.git_list_state.selected() else { return };
+    let Some(entry) = app.git_entries.get(i) else { return };
+    git::spawn_diff(app.project_path.clone(), entry.path.clone(), entry.untracked, tx.clone());
+}
 
-    // Editor Area
-    let editor_title = if app.focus == Focus::Editor { " Editor (Editing Mode) " } else { " Editor " };
-    let editor_block = Paragraph::new(app.current_content.as_str())
-        .block(Block::default().borders(Borders::ALL).title(editor_title)
-        .border_style(if app.focus == Focus::Editor { Style::default().fg(Color::Cyan) } else { Style::default() }));
-    f.render_widget(editor_block, body_chunks[1]);
+fn toggle_stage_selected(app: &App, tx: &tokio::sync::mpsc::UnboundedSender<Event>) {
+    let Some(i) = app.git_list_state.selected() else { return };
+    let Some(entry) = app.git_entries.get(i) else { return };
+    if entry.staged {
+        git::spawn_unstage(app.project_path.clone(), entry.path.clone(), tx.clone());
+    } else {
+        git::spawn_stage(app.project_path.clone(), entry.path.clone(), tx.clone());
+    }
+}
 
-    // 3. Logs
-    let log_items: Vec<ListItem> = app.logs.iter().rev().take(10).map(|l| ListItem::new(l.as_str())).collect();
-    let logs = List::new(log_items).block(Block::default().borders(Borders::ALL).title(" Console Output "));
-    f.render_widget(logs, chunks[2]);
+async fn start_or_restart_docker(docker: &mut Option<DockerManager>, app: &App, tx: &tokio::sync::mpsc::UnboundedSender<Event>) {
+    if docker.is_none() {
+        match DockerManager::connect(app.project_path.clone()) {
+            Ok(manager) => *docker = Some(manager),
+            Err(err) => {
+                let _ = tx.send(Event::DockerLog(format!("Failed to connect to Docker: {err}")));
+                return;
+            }
+        }
+    }
+    if let Some(manager) = docker {
+        let result = if manager.is_running().await {
+            manager.restart(tx.clone()).await
+        } else {
+            manager.start(tx.clone()).await
+        };
+        if let Err(err) = result {
+            let _ = tx.send(Event::DockerLog(format!("Docker error: {err}")));
+        }
+    }
+}
 
-    // 4. Help Bar
-    let help_menu = Paragraph::new(" [q]Quit | [Tab]Switch Focus | [r]Run Docker | [g]Git Status | [Ctrl+s]Save ");
-    f.render_widget(help_menu, chunks[3]);
+async fn stop_docker(docker: &mut Option<DockerManager>, app: &mut App) {
+    if let Some(manager) = docker {
+        if let Err(err) = manager.stop_and_remove().await {
+            app.logs.push(format!("[Docker] Error stopping container: {err}"));
+        } else {
+            app.status = "OFFLINE".to_string();
+            app.logs.push("[Docker] Container stopped and removed.".into());
+        }
+    }
+    *docker = None;
 }
@@ -0,0 +1,177 @@
+use ratatui::{prelude::*, widgets::*};
+
+use crate::app::{App, Focus};
+
+pub fn ui(f: &mut Frame, app: &mut App) {
+    let size = f.size();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Min(10),   // Middle (Files + Editor)
+            Constraint::Length(8), // Footer (Logs)
+            Constraint::Length(1), // Help Bar
+        ])
+        .split(size);
+
+    // 1. Header
+    let status_color = if app.status == "RUNNING" { Color::Green } else { Color::Yellow };
+    let header = Paragraph::new(format!(" Docusaurus Manager | Status: {} | Path: {}", app.status, app.project_path.display()))
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(status_color)))
+        .style(Style::default().fg(Color::White).bold());
+    f.render_widget(header, chunks[0]);
+
+    // 2. Middle Area (Horizontal)
+    let body_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(chunks[1]);
+
+    if app.focus == Focus::Git {
+        render_git_panel(f, app, body_chunks);
+    } else {
+        render_files_and_editor(f, app, body_chunks);
+    }
+
+    // 3. Logs
+    let log_items: Vec<ListItem> = app.logs.iter().rev().take(10).map(|l| ListItem::new(l.as_str())).collect();
+    let logs = List::new(log_items).block(Block::default().borders(Borders::ALL).title(" Console Output "));
+    f.render_widget(logs, chunks[2]);
+
+    // 4. Help Bar
+    let help_menu = Paragraph::new(app.help_text.as_str());
+    f.render_widget(help_menu, chunks[3]);
+
+    if app.git_commit_input.is_some() {
+        render_commit_prompt(f, app, size);
+    }
+}
+
+fn render_files_and_editor(f: &mut Frame, app: &mut App, body_chunks: std::rc::Rc<[Rect]>) {
+    // File List
+    let items: Vec<ListItem> = app.files
+        .iter()
+        .map(|p| {
+            let filename = p.file_name().unwrap().to_string_lossy();
+            ListItem::new(format!(" 📄 {}", filename))
+        })
+        .collect();
+
+    let list_block = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Files (Up/Down) "))
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White))
+        .highlight_symbol(">> ");
+
+    f.render_stateful_widget(list_block, body_chunks[0], &mut app.file_list_state);
+
+    // Editor Area
+    let dirty_marker = if app.dirty { " *" } else { "" };
+    let highlight_marker = if app.highlight_enabled { " [highlighted]" } else { "" };
+    let editor_title = if app.focus == Focus::Editor {
+        format!(" Editor (Editing Mode){}{} ", dirty_marker, highlight_marker)
+    } else {
+        format!(" Editor{}{} ", dirty_marker, highlight_marker)
+    };
+    let editor_area = body_chunks[1];
+    let inner_height = editor_area.height.saturating_sub(2) as usize;
+    app.scroll_editor_to_cursor(inner_height);
+
+    let visible_lines: Vec<String> = app
+        .lines
+        .iter()
+        .skip(app.editor_scroll)
+        .take(inner_height.max(1))
+        .cloned()
+        .collect();
+
+    let text: Text = if app.highlight_enabled {
+        Text::from(app.highlighter.highlight_lines(&visible_lines))
+    } else {
+        Text::from(visible_lines.iter().map(|l| Line::raw(l.clone())).collect::<Vec<_>>())
+    };
+
+    let editor_block = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(editor_title)
+            .border_style(if app.focus == Focus::Editor { Style::default().fg(Color::Cyan) } else { Style::default() }),
+    );
+    f.render_widget(editor_block, editor_area);
+
+    if app.focus == Focus::Editor {
+        let cursor_x = editor_area.x + 1 + app.cursor.1 as u16;
+        let cursor_y = editor_area.y + 1 + (app.cursor.0 - app.editor_scroll) as u16;
+        f.set_cursor(cursor_x, cursor_y);
+    }
+}
+
+// Gitパネル: ステータス一覧とdiffペインをファイル一覧/エディタの代わりに表示する
+fn render_git_panel(f: &mut Frame, app: &mut App, body_chunks: std::rc::Rc<[Rect]>) {
+    let entry_items: Vec<ListItem> = app
+        .git_entries
+        .iter()
+        .map(|e| {
+            let marker = if e.untracked {
+                "??"
+            } else if e.staged {
+                " M"
+            } else if e.unstaged {
+                " m"
+            } else {
+                "  "
+            };
+            ListItem::new(format!(" {} {}", marker, e.path))
+        })
+        .collect();
+
+    let list_block = List::new(entry_items)
+        .block(Block::default().borders(Borders::ALL).title(" Git Status (Enter: stage/unstage, c: commit) "))
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White))
+        .highlight_symbol(">> ");
+    f.render_stateful_widget(list_block, body_chunks[0], &mut app.git_list_state);
+
+    let diff_area = body_chunks[1];
+    let inner_height = diff_area.height.saturating_sub(2) as usize;
+    let visible_diff: Vec<Line> = app
+        .git_diff
+        .iter()
+        .skip(app.git_diff_scroll)
+        .take(inner_height.max(1))
+        .map(|l| Line::styled(l.clone(), diff_line_style(l)))
+        .collect();
+
+    let diff_block = Paragraph::new(Text::from(visible_diff))
+        .block(Block::default().borders(Borders::ALL).title(" Diff (Left/Right: scroll) ").border_style(Style::default().fg(Color::Cyan)));
+    f.render_widget(diff_block, diff_area);
+}
+
+/// Colorizes a plain-text diff line by its leading marker, the same way `git diff --color`
+/// would, since ratatui doesn't interpret ANSI escapes on its own.
+fn diff_line_style(line: &str) -> Style {
+    if line.starts_with("+++") || line.starts_with("---") {
+        Style::default().fg(Color::White).bold()
+    } else if line.starts_with('+') {
+        Style::default().fg(Color::Green)
+    } else if line.starts_with('-') {
+        Style::default().fg(Color::Red)
+    } else if line.starts_with("@@") {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    }
+}
+
+fn render_commit_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let width = area.width.saturating_sub(10).max(20).min(60);
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + area.height / 2 - 1,
+        width,
+        height: 3,
+    };
+    f.render_widget(Clear, popup);
+    let message = app.git_commit_input.as_deref().unwrap_or("");
+    let prompt = Paragraph::new(message).block(Block::default().borders(Borders::ALL).title(" Commit Message (Enter: commit, Esc: cancel) "));
+    f.render_widget(prompt, popup);
+    f.set_cursor(popup.x + 1 + message.chars().count() as u16, popup.y + 1);
+}
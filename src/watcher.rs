@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use notify::{Event as NotifyEvent, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::event::Event;
+
+/// How long to wait after the last filesystem notification before acting on a burst of them,
+/// so a single save doesn't trigger dozens of refreshes.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Starts watching `path` recursively and forwards debounced `.md`/`.mdx` changes into `tx`.
+/// The returned watcher must be kept alive for as long as watching should continue.
+pub fn spawn_watcher(path: PathBuf, tx: UnboundedSender<Event>) -> notify::Result<RecommendedWatcher> {
+    let (raw_tx, mut raw_rx) = tokio::sync::mpsc::unbounded_channel::<NotifyEvent>();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<NotifyEvent>| {
+        if let Ok(event) = res {
+            let _ = raw_tx.send(event);
+        }
+    })?;
+    watcher.watch(&path, RecursiveMode::Recursive)?;
+
+    tokio::spawn(async move {
+        let mut pending: HashMap<PathBuf, EventKind> = HashMap::new();
+        loop {
+            let Some(first) = raw_rx.recv().await else { break };
+            record(&mut pending, first);
+            while let Ok(Some(ev)) = tokio::time::timeout(DEBOUNCE, raw_rx.recv()).await {
+                record(&mut pending, ev);
+            }
+
+            for (path, kind) in pending.drain() {
+                if !is_relevant(&path) {
+                    continue;
+                }
+                let mapped = match kind {
+                    EventKind::Create(_) => Event::FileCreated(path),
+                    EventKind::Remove(_) => Event::FileRemoved(path),
+                    _ => Event::FileChanged(path),
+                };
+                if tx.send(mapped).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(watcher)
+}
+
+fn record(pending: &mut HashMap<PathBuf, EventKind>, event: NotifyEvent) {
+    for path in event.paths {
+        pending.insert(path, event.kind);
+    }
+}
+
+fn is_relevant(path: &std::path::Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "md" || ext == "mdx")
+}